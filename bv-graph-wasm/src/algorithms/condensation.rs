@@ -0,0 +1,268 @@
+//! Strongly-connected-component condensation.
+//!
+//! Critical-path analysis assumes a DAG. `condensation` collapses any graph
+//! (cyclic or not) into its strongly-connected components, yielding a DAG
+//! of super-nodes that `slack`/`cpm` can run on even when the original
+//! graph has feedback loops.
+
+use crate::algorithms::control::{Cancelled, Control};
+use crate::graph::DiGraph;
+
+
+/// Run Tarjan's SCC algorithm over `graph`.
+///
+/// Returns one `Vec<usize>` of member node indices per strongly-connected
+/// component, in the order components were closed off (reverse
+/// topological order of the condensation).
+pub fn strongly_connected_components(graph: &DiGraph) -> Vec<Vec<usize>> {
+    strongly_connected_components_with_control(graph, &Control::uncancellable())
+        .expect("uncancellable control never returns Cancelled")
+}
+
+/// Like [`strongly_connected_components`], but checks `control.should_cancel`
+/// periodically while visiting nodes, returning `Err(Cancelled)` if it
+/// fires, and invokes `control.on_progress` every `control.progress_every`
+/// nodes opened.
+pub fn strongly_connected_components_with_control(
+    graph: &DiGraph,
+    control: &Control,
+) -> Result<Vec<Vec<usize>>, Cancelled> {
+    let n = graph.len();
+    let mut tarjan = Tarjan {
+        graph,
+        index: vec![None; n],
+        lowlink: vec![0; n],
+        on_stack: vec![false; n],
+        stack: Vec::new(),
+        next_index: 0,
+        sccs: Vec::new(),
+    };
+    for v in 0..n {
+        if tarjan.index[v].is_none() {
+            tarjan.visit(v, control)?;
+        }
+    }
+    Ok(tarjan.sccs)
+}
+
+struct Tarjan<'a> {
+    graph: &'a DiGraph,
+    index: Vec<Option<usize>>,
+    lowlink: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    next_index: usize,
+    sccs: Vec<Vec<usize>>,
+}
+
+impl<'a> Tarjan<'a> {
+    /// Iterative single-DFS visit of `start`, maintaining an explicit stack
+    /// of (node, next successor index to examine) so deep graphs don't blow
+    /// the call stack.
+    fn visit(&mut self, start: usize, control: &Control) -> Result<(), Cancelled> {
+        let mut call_stack: Vec<(usize, usize)> = vec![(start, 0)];
+        self.open(start, control)?;
+
+        while let Some(&(v, succ_idx)) = call_stack.last() {
+            let succs = self.graph.successors_slice(v);
+            if succ_idx < succs.len() {
+                let w = succs[succ_idx];
+                call_stack.last_mut().unwrap().1 += 1;
+                if self.index[w].is_none() {
+                    self.open(w, control)?;
+                    call_stack.push((w, 0));
+                } else if self.on_stack[w] {
+                    self.lowlink[v] = self.lowlink[v].min(self.index[w].unwrap());
+                }
+            } else {
+                call_stack.pop();
+                if self.lowlink[v] == self.index[v].unwrap() {
+                    self.pop_scc(v);
+                }
+                if let Some(&(parent, _)) = call_stack.last() {
+                    self.lowlink[parent] = self.lowlink[parent].min(self.lowlink[v]);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn open(&mut self, v: usize, control: &Control) -> Result<(), Cancelled> {
+        control.tick(self.next_index, self.index.len())?;
+        self.index[v] = Some(self.next_index);
+        self.lowlink[v] = self.next_index;
+        self.next_index += 1;
+        self.stack.push(v);
+        self.on_stack[v] = true;
+        Ok(())
+    }
+
+    fn pop_scc(&mut self, root: usize) {
+        let mut scc = Vec::new();
+        loop {
+            let w = self.stack.pop().unwrap();
+            self.on_stack[w] = false;
+            scc.push(w);
+            if w == root {
+                break;
+            }
+        }
+        self.sccs.push(scc);
+    }
+}
+
+/// Collapse `graph` into its condensation: a DAG where each strongly
+/// connected component becomes a single super-node, and inter-component
+/// edges are collapsed (parallel edges deduplicated).
+///
+/// Returns the condensation graph together with each super-node's member
+/// node indices in the original graph, indexed in condensation order.
+pub fn condensation(graph: &DiGraph) -> (DiGraph, Vec<Vec<usize>>) {
+    condensation_with_control(graph, &Control::uncancellable())
+        .expect("uncancellable control never returns Cancelled")
+}
+
+/// Like [`condensation`], but checks `control.should_cancel` periodically
+/// while running Tarjan's algorithm, returning `Err(Cancelled)` if it
+/// fires, and invokes `control.on_progress` every `control.progress_every`
+/// nodes opened.
+pub fn condensation_with_control(
+    graph: &DiGraph,
+    control: &Control,
+) -> Result<(DiGraph, Vec<Vec<usize>>), Cancelled> {
+    let sccs = strongly_connected_components_with_control(graph, control)?;
+
+    let mut component_of = vec![0usize; graph.len()];
+    for (comp, members) in sccs.iter().enumerate() {
+        for &v in members {
+            component_of[v] = comp;
+        }
+    }
+
+    let mut condensed = DiGraph::new();
+    for comp in 0..sccs.len() {
+        condensed.add_node(&format!("scc_{}", comp));
+    }
+
+    let mut seen_edges = std::collections::HashSet::new();
+    for v in 0..graph.len() {
+        for &w in graph.successors_slice(v) {
+            let (cu, cw) = (component_of[v], component_of[w]);
+            if cu != cw && seen_edges.insert((cu, cw)) {
+                condensed.add_edge(cu, cw);
+            }
+        }
+    }
+
+    Ok((condensed, sccs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scc_acyclic_graph_is_all_singletons() {
+        // a -> b -> c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let mut sccs = strongly_connected_components(&graph);
+        for scc in &mut sccs {
+            scc.sort();
+        }
+        sccs.sort();
+        assert_eq!(sccs, vec![vec![a], vec![b], vec![c]]);
+    }
+
+    #[test]
+    fn test_scc_simple_cycle() {
+        // a -> b -> c -> a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+
+        let sccs = strongly_connected_components(&graph);
+        assert_eq!(sccs.len(), 1);
+        let mut only = sccs[0].clone();
+        only.sort();
+        assert_eq!(only, vec![a, b, c]);
+    }
+
+    #[test]
+    fn test_condensation_collapses_cycle_into_dag() {
+        // a -> b -> c -> a, plus c -> d (d outside the cycle)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+        graph.add_edge(c, d);
+
+        let (condensed, members) = condensation(&graph);
+        assert_eq!(condensed.len(), 2);
+        assert_eq!(members.len(), 2);
+
+        let cycle_comp = members.iter().position(|m| m.len() == 3).unwrap();
+        let singleton_comp = members.iter().position(|m| m.len() == 1).unwrap();
+        assert!(members[singleton_comp].contains(&d));
+        assert_eq!(
+            condensed.successors_slice(cycle_comp),
+            &[singleton_comp][..]
+        );
+    }
+
+    #[test]
+    fn test_condensation_dedups_parallel_inter_scc_edges() {
+        // a <-> b form one SCC and both point at c, so the original edges
+        // a -> c and b -> c collapse into a single condensation edge.
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        graph.add_edge(a, c);
+        graph.add_edge(b, c);
+
+        let (condensed, members) = condensation(&graph);
+        assert_eq!(condensed.len(), 2);
+        let cycle_comp = members.iter().position(|m| m.len() == 2).unwrap();
+        let singleton_comp = members.iter().position(|m| m.contains(&c)).unwrap();
+        assert_eq!(
+            condensed.successors_slice(cycle_comp),
+            &[singleton_comp][..]
+        );
+    }
+
+    #[test]
+    fn test_condensation_with_control_cancels() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        let always_cancel = || true;
+        let control = Control {
+            should_cancel: &always_cancel,
+            on_progress: None,
+            progress_every: 1,
+        };
+        assert_eq!(
+            condensation_with_control(&graph, &control).err(),
+            Some(Cancelled)
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Cancellation and progress reporting shared by the long-running passes in
+//! this module (slack, CPM, condensation, feedback arc set, dominators).
+//!
+//! Each pass exposes a `*_with_control` variant that checks
+//! `should_cancel` periodically inside its main loops and reports progress
+//! every `progress_every` processed nodes, plus a plain variant that is a
+//! thin wrapper passing a callback that never cancels and no progress
+//! callback — so existing callers are unaffected while long-running
+//! computations become interruptible from a UI thread.
+
+/// Returned by a `*_with_control` pass when `should_cancel` fired before
+/// completion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+/// Shared "never cancel" callback backing [`Control::uncancellable`], so
+/// every plain (non-`_with_control`) entry point across the module can
+/// reference one definition instead of redeclaring it per file.
+static NEVER_CANCEL: fn() -> bool = || false;
+
+/// Cancellation and progress hooks threaded through the control-aware
+/// passes. `should_cancel` is checked on every [`Control::tick`];
+/// `on_progress` is invoked every `progress_every` processed nodes
+/// ([`Control::uncancellable`] never cancels and reports no progress).
+pub struct Control<'a> {
+    pub should_cancel: &'a dyn Fn() -> bool,
+    pub on_progress: Option<&'a dyn Fn(usize, usize)>,
+    pub progress_every: usize,
+}
+
+impl Control<'static> {
+    /// A control that never cancels and never reports progress, used by
+    /// the plain (non-`_with_control`) entry points.
+    pub fn uncancellable() -> Control<'static> {
+        Control {
+            should_cancel: &NEVER_CANCEL,
+            on_progress: None,
+            progress_every: usize::MAX,
+        }
+    }
+}
+
+impl<'a> Control<'a> {
+    /// Check for cancellation and, every `progress_every` processed nodes,
+    /// report `(done, total)`.
+    pub fn tick(&self, done: usize, total: usize) -> Result<(), Cancelled> {
+        if (self.should_cancel)() {
+            return Err(Cancelled);
+        }
+        if let Some(on_progress) = self.on_progress {
+            if self.progress_every != 0 && done % self.progress_every == 0 {
+                on_progress(done, total);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+//! Weighted critical-path method (CPM) scheduling.
+//!
+//! Unlike the unit-weight [`slack`](crate::algorithms::slack::slack) pass,
+//! `cpm` accounts for per-task durations and optional edge lags, producing
+//! the four classic CPM quantities for every node: earliest-start (ES),
+//! earliest-finish (EF), latest-start (LS) and latest-finish (LF).
+//!
+//! # Algorithm
+//! 1. Topological sort.
+//! 2. Forward pass: `ES[v] = max over preds u of (EF[u] + lag(u, v))`,
+//!    `EF[v] = ES[v] + duration[v]`.
+//! 3. Backward pass, seeded from the project makespan (the largest EF):
+//!    `LF[v] = min over succs w of (LS[w] - lag(v, w))`, with sink nodes'
+//!    `LF` set to the makespan.
+//! 4. `slack[v] = LS[v] - ES[v]`.
+//!
+//! Returns `None` for cyclic graphs; callers that need to analyze cyclic
+//! dependency graphs should condense them first (see
+//! [`condensation`](crate::algorithms::condensation::condensation)).
+
+use crate::algorithms::control::{Cancelled, Control};
+use crate::algorithms::topo::topological_sort_with_control;
+use crate::graph::DiGraph;
+
+
+/// Per-node CPM scheduling quantities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CpmResult {
+    /// Earliest start time of each node.
+    pub es: Vec<f64>,
+    /// Earliest finish time of each node.
+    pub ef: Vec<f64>,
+    /// Latest start time of each node.
+    pub ls: Vec<f64>,
+    /// Latest finish time of each node.
+    pub lf: Vec<f64>,
+    /// Slack (= ls - es) of each node. Zero-slack nodes are on the critical path.
+    pub slack: Vec<f64>,
+}
+
+/// Run the weighted critical-path method over `graph`.
+///
+/// `durations[v]` is the duration of node `v`; it must have one entry per
+/// node. `edge_lags`, if present, is consulted for the lag on edge `(u, v)`
+/// and defaults to `0.0` when absent or when no callback is given.
+///
+/// Returns `None` if `graph` contains a cycle.
+pub fn cpm(
+    graph: &DiGraph,
+    durations: &[f64],
+    edge_lags: Option<&dyn Fn(usize, usize) -> f64>,
+) -> Option<CpmResult> {
+    cpm_with_control(graph, durations, edge_lags, &Control::uncancellable())
+        .expect("uncancellable control never returns Cancelled")
+}
+
+/// Like [`cpm`], but checks `control.should_cancel` periodically inside the
+/// topological sort and the forward/backward passes, returning
+/// `Err(Cancelled)` if it fires, and invokes `control.on_progress` every
+/// `control.progress_every` processed nodes. `Ok(None)` still means "`graph`
+/// contains a cycle", same as `cpm`.
+///
+/// # Panics
+/// Panics if `durations.len() != graph.len()`.
+pub fn cpm_with_control(
+    graph: &DiGraph,
+    durations: &[f64],
+    edge_lags: Option<&dyn Fn(usize, usize) -> f64>,
+    control: &Control,
+) -> Result<Option<CpmResult>, Cancelled> {
+    let n = graph.len();
+    assert_eq!(
+        durations.len(),
+        n,
+        "durations must have one entry per node"
+    );
+    if n == 0 {
+        return Ok(Some(CpmResult {
+            es: Vec::new(),
+            ef: Vec::new(),
+            ls: Vec::new(),
+            lf: Vec::new(),
+            slack: Vec::new(),
+        }));
+    }
+
+    let order = match topological_sort_with_control(graph, control)? {
+        Some(o) => o,
+        None => return Ok(None),
+    };
+    let lag = |u: usize, v: usize| edge_lags.map_or(0.0, |f| f(u, v));
+
+    // Forward pass.
+    let mut es = vec![0.0f64; n];
+    let mut ef = vec![0.0f64; n];
+    for (done, &v) in order.iter().enumerate() {
+        control.tick(done, n)?;
+        es[v] = graph
+            .predecessors_slice(v)
+            .iter()
+            .map(|&u| ef[u] + lag(u, v))
+            .fold(0.0, f64::max);
+        ef[v] = es[v] + durations[v];
+    }
+
+    let makespan = ef.iter().cloned().fold(0.0, f64::max);
+
+    // Backward pass, seeded from the makespan.
+    let mut ls = vec![0.0f64; n];
+    let mut lf = vec![0.0f64; n];
+    for (done, &v) in order.iter().rev().enumerate() {
+        control.tick(done, n)?;
+        let succs = graph.successors_slice(v);
+        lf[v] = if succs.is_empty() {
+            makespan
+        } else {
+            succs
+                .iter()
+                .map(|&w| ls[w] - lag(v, w))
+                .fold(f64::INFINITY, f64::min)
+        };
+        ls[v] = lf[v] - durations[v];
+    }
+
+    let slack = (0..n).map(|v| ls[v] - es[v]).collect();
+
+    Ok(Some(CpmResult {
+        es,
+        ef,
+        ls,
+        lf,
+        slack,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpm_chain() {
+        // a(2) -> b(3) -> c(1)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let result = cpm(&graph, &[2.0, 3.0, 1.0], None).unwrap();
+        assert_eq!(result.es, vec![0.0, 2.0, 5.0]);
+        assert_eq!(result.ef, vec![2.0, 5.0, 6.0]);
+        assert_eq!(result.slack, vec![0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_cpm_parallel_chains_have_slack() {
+        // a(1) -> b(1) -> c(1)   (length 3, critical)
+        // d(1) -> e(1)           (length 2, slack 1)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(d, e);
+
+        let result = cpm(&graph, &[1.0, 1.0, 1.0, 1.0, 1.0], None).unwrap();
+        assert_eq!(result.slack[a], 0.0);
+        assert_eq!(result.slack[b], 0.0);
+        assert_eq!(result.slack[c], 0.0);
+        assert_eq!(result.slack[d], 1.0);
+        assert_eq!(result.slack[e], 1.0);
+    }
+
+    #[test]
+    fn test_cpm_with_edge_lags() {
+        // a(1) -[lag 2]-> b(1): b cannot start until 2 units after a finishes.
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let lag = |u: usize, v: usize| if (u, v) == (a, b) { 2.0 } else { 0.0 };
+        let result = cpm(&graph, &[1.0, 1.0], Some(&lag)).unwrap();
+        assert_eq!(result.es[b], 3.0);
+        assert_eq!(result.ef[b], 4.0);
+    }
+
+    #[test]
+    fn test_cpm_cyclic_returns_none() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        assert_eq!(cpm(&graph, &[1.0, 1.0], None), None);
+    }
+
+    #[test]
+    fn test_cpm_matches_unit_weight_slack() {
+        // Diamond: unit durations should reproduce the unit-weight slack values.
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let result = cpm(&graph, &[1.0, 1.0, 1.0, 1.0], None).unwrap();
+        assert_eq!(result.slack, vec![0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_cpm_with_control_cancels() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let always_cancel = || true;
+        let control = Control {
+            should_cancel: &always_cancel,
+            on_progress: None,
+            progress_every: 1,
+        };
+        let result = cpm_with_control(&graph, &[1.0, 1.0], None, &control);
+        assert_eq!(result, Err(Cancelled));
+    }
+
+    #[test]
+    fn test_cpm_with_control_reports_progress() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let never_cancel = || false;
+        let calls = std::cell::RefCell::new(Vec::new());
+        let on_progress = |done: usize, total: usize| calls.borrow_mut().push((done, total));
+        let control = Control {
+            should_cancel: &never_cancel,
+            on_progress: Some(&on_progress),
+            progress_every: 1,
+        };
+
+        cpm_with_control(&graph, &[1.0, 1.0, 1.0], None, &control).unwrap();
+        assert!(!calls.borrow().is_empty());
+    }
+}
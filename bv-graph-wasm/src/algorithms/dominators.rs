@@ -0,0 +1,276 @@
+//! Dominator tree computation.
+//!
+//! Node `d` dominates node `n` if every path from `root` to `n` passes
+//! through `d`. This answers "which upstream task, if it slips, necessarily
+//! delays a given downstream task" — distinguishing tasks that merely sit
+//! on a long path (see [`slack`](crate::algorithms::slack::slack)) from
+//! tasks that are true bottlenecks gating everything below them.
+//!
+//! Implements the iterative Cooper–Harvey–Kennedy algorithm: process nodes
+//! in reverse postorder, intersecting each node's already-processed
+//! predecessors' immediate dominators via a two-finger walk up the
+//! dominator tree, until a fixpoint is reached.
+
+use crate::algorithms::control::{Cancelled, Control};
+use crate::graph::DiGraph;
+
+
+/// The dominator tree of a graph rooted at a chosen start node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dominators {
+    root: usize,
+    /// `idom[v]` is `v`'s immediate dominator, or `None` for `root` and for
+    /// nodes unreachable from `root`.
+    idom: Vec<Option<usize>>,
+}
+
+impl Dominators {
+    /// The immediate dominator of `n`: the unique closest node (other than
+    /// `n` itself) that dominates `n`. `None` for `root` and for nodes
+    /// unreachable from `root`.
+    pub fn immediate_dominator(&self, n: usize) -> Option<usize> {
+        self.idom[n]
+    }
+
+    /// All dominators of `n`, nearest first, ending at `root`. Empty for
+    /// nodes unreachable from `root`.
+    pub fn dominators_of(&self, n: usize) -> impl Iterator<Item = usize> + '_ {
+        DominatorsOf {
+            doms: self,
+            current: if n == self.root || self.idom[n].is_some() {
+                Some(n)
+            } else {
+                None
+            },
+        }
+    }
+}
+
+struct DominatorsOf<'a> {
+    doms: &'a Dominators,
+    current: Option<usize>,
+}
+
+impl<'a> Iterator for DominatorsOf<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let v = self.current?;
+        self.current = self.doms.idom[v];
+        Some(v)
+    }
+}
+
+/// Compute the dominator tree of `graph` rooted at `root`.
+///
+/// Nodes unreachable from `root` have no immediate dominator and are
+/// excluded from [`Dominators::dominators_of`].
+pub fn dominators(graph: &DiGraph, root: usize) -> Dominators {
+    dominators_with_control(graph, root, &Control::uncancellable())
+        .expect("uncancellable control never returns Cancelled")
+}
+
+/// Like [`dominators`], but checks `control.should_cancel` periodically
+/// inside the fixpoint loop, returning `Err(Cancelled)` if it fires, and
+/// invokes `control.on_progress` every `control.progress_every` nodes
+/// processed within a pass.
+pub fn dominators_with_control(
+    graph: &DiGraph,
+    root: usize,
+    control: &Control,
+) -> Result<Dominators, Cancelled> {
+    let n = graph.len();
+    let rpo = reverse_postorder(graph, root);
+
+    // rpo_number[v] = position of v in the reverse-postorder list, used by
+    // the two-finger intersection walk below. Unreachable nodes keep usize::MAX.
+    let mut rpo_number = vec![usize::MAX; n];
+    for (i, &v) in rpo.iter().enumerate() {
+        rpo_number[v] = i;
+    }
+
+    let mut idom: Vec<Option<usize>> = vec![None; n];
+    idom[root] = Some(root);
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (done, &v) in rpo.iter().skip(1).enumerate() {
+            control.tick(done, rpo.len())?;
+            let mut new_idom: Option<usize> = None;
+            for &pred in graph.predecessors_slice(v) {
+                if idom[pred].is_none() {
+                    continue; // predecessor not yet processed (or unreachable)
+                }
+                new_idom = Some(match new_idom {
+                    None => pred,
+                    Some(other) => intersect(&idom, &rpo_number, pred, other),
+                });
+            }
+            if idom[v] != new_idom {
+                idom[v] = new_idom;
+                changed = true;
+            }
+        }
+    }
+
+    idom[root] = None; // root has no dominator of its own
+    Ok(Dominators { root, idom })
+}
+
+/// Two-finger walk up the dominator tree: repeatedly advance whichever of
+/// `a`/`b` has the larger reverse-postorder number until both fingers meet
+/// at their common dominator.
+fn intersect(idom: &[Option<usize>], rpo_number: &[usize], a: usize, b: usize) -> usize {
+    let mut a = a;
+    let mut b = b;
+    while a != b {
+        while rpo_number[a] > rpo_number[b] {
+            a = idom[a].unwrap();
+        }
+        while rpo_number[b] > rpo_number[a] {
+            b = idom[b].unwrap();
+        }
+    }
+    a
+}
+
+/// Reverse postorder of the nodes reachable from `root` via a DFS.
+fn reverse_postorder(graph: &DiGraph, root: usize) -> Vec<usize> {
+    let n = graph.len();
+    let mut visited = vec![false; n];
+    let mut postorder = Vec::new();
+    // Explicit stack of (node, next successor index) to avoid recursion.
+    let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+    visited[root] = true;
+
+    while let Some(&(v, succ_idx)) = stack.last() {
+        let succs = graph.successors_slice(v);
+        if succ_idx < succs.len() {
+            let w = succs[succ_idx];
+            stack.last_mut().unwrap().1 += 1;
+            if !visited[w] {
+                visited[w] = true;
+                stack.push((w, 0));
+            }
+        } else {
+            postorder.push(v);
+            stack.pop();
+        }
+    }
+
+    postorder.reverse();
+    postorder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominators_chain() {
+        // a -> b -> c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let doms = dominators(&graph, a);
+        assert_eq!(doms.immediate_dominator(a), None);
+        assert_eq!(doms.immediate_dominator(b), Some(a));
+        assert_eq!(doms.immediate_dominator(c), Some(b));
+        assert_eq!(doms.dominators_of(c).collect::<Vec<_>>(), vec![c, b, a]);
+    }
+
+    #[test]
+    fn test_dominators_diamond_root_dominates_sink() {
+        //     a
+        //    / \
+        //   b   c
+        //    \ /
+        //     d
+        // Neither b nor c dominates d (d is reachable without going through
+        // either one individually), so d's immediate dominator is a.
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let doms = dominators(&graph, a);
+        assert_eq!(doms.immediate_dominator(b), Some(a));
+        assert_eq!(doms.immediate_dominator(c), Some(a));
+        assert_eq!(doms.immediate_dominator(d), Some(a));
+    }
+
+    #[test]
+    fn test_dominators_bottleneck_node() {
+        //     a
+        //    / \
+        //   b   c
+        //    \ /
+        //     e  (bottleneck: every downstream path passes through here)
+        //     |
+        //     f
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let e = graph.add_node("e");
+        let f = graph.add_node("f");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, e);
+        graph.add_edge(c, e);
+        graph.add_edge(e, f);
+
+        let doms = dominators(&graph, a);
+        assert_eq!(doms.immediate_dominator(e), Some(a));
+        assert_eq!(doms.immediate_dominator(f), Some(e));
+        assert_eq!(doms.dominators_of(f).collect::<Vec<_>>(), vec![f, e, a]);
+    }
+
+    #[test]
+    fn test_dominators_unreachable_node_has_no_dominators() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let _unreachable = graph.add_node("isolated");
+        graph.add_edge(a, b);
+
+        let doms = dominators(&graph, a);
+        assert_eq!(doms.immediate_dominator(_unreachable), None);
+        assert_eq!(doms.dominators_of(_unreachable).collect::<Vec<_>>(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_dominators_root_has_no_immediate_dominator() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let doms = dominators(&graph, a);
+        assert_eq!(doms.immediate_dominator(a), None);
+        assert_eq!(doms.dominators_of(a).collect::<Vec<_>>(), vec![a]);
+    }
+
+    #[test]
+    fn test_dominators_with_control_cancels() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let always_cancel = || true;
+        let control = Control {
+            should_cancel: &always_cancel,
+            on_progress: None,
+            progress_every: 1,
+        };
+        assert_eq!(dominators_with_control(&graph, a, &control), Err(Cancelled));
+    }
+}
@@ -3,11 +3,26 @@
 //! Slack measures how much a task can be delayed without affecting
 //! the overall project completion time (critical path length).
 //! Nodes with zero slack are on the critical path.
+//!
+//! This is the unit-duration special case of the weighted
+//! [`cpm`](crate::algorithms::cpm::cpm) pass: every node has duration 1,
+//! so `dist_from_start[v]` below is exactly CPM's EF and `dist_to_end[v]`
+//! is makespan minus LS, expressed in node counts rather than elapsed time.
+//!
+//! Cyclic graphs have no topological order, so `slack` falls back to
+//! [`condensation`](crate::algorithms::condensation::condensation): each
+//! strongly-connected component becomes one super-node whose "length" is
+//! its member count, the same forward/backward passes run on that
+//! condensation DAG, and every member node inherits its super-node's
+//! slack.
 
-use crate::algorithms::topo::topological_sort;
+use crate::algorithms::condensation::condensation_with_control;
+use crate::algorithms::control::{Cancelled, Control};
+use crate::algorithms::topo::{topological_sort, topological_sort_with_control};
 use crate::graph::DiGraph;
 
-/// Compute slack for each node in a DAG.
+
+/// Compute slack for each node in a graph, treating every node as unit length.
 ///
 /// Slack = (critical path length) - (longest path through this node)
 ///
@@ -19,61 +34,146 @@ use crate::graph::DiGraph;
 /// 3. Backward pass: compute longest distance to any end node
 /// 4. Slack = max_path_length - (forward + backward distances)
 ///
+/// For per-task durations and edge lags, use [`cpm`](crate::algorithms::cpm::cpm)
+/// instead, which computes the same quantity as `slack == ls - es` under
+/// `durations = vec![1.0; n]`.
+///
 /// # Returns
-/// Vector of slack values indexed by node. Returns zeros for cyclic graphs.
+/// Vector of slack values indexed by node. Cyclic graphs are condensed into
+/// their strongly-connected components first (see module docs), so every
+/// node still gets a meaningful slack rather than a flat zero.
 pub fn slack(graph: &DiGraph) -> Vec<f64> {
+    slack_with_control(graph, &Control::uncancellable())
+        .expect("uncancellable control never returns Cancelled")
+}
+
+/// Like [`slack`], but checks `control.should_cancel` periodically inside
+/// the topological sort and the forward/backward loops (and condensation's
+/// SCC pass and its own topological sort, for cyclic graphs), returning
+/// `Err(Cancelled)` if it fires, and invokes `control.on_progress` every
+/// `control.progress_every` processed nodes.
+pub fn slack_with_control(graph: &DiGraph, control: &Control) -> Result<Vec<f64>, Cancelled> {
     let n = graph.len();
     if n == 0 {
-        return Vec::new();
+        return Ok(Vec::new());
     }
 
-    // Get topological order (None if cyclic)
-    let order = match topological_sort(graph) {
-        Some(o) => o,
-        None => return vec![0.0; n], // Return zeros for cyclic graphs
-    };
+    match topological_sort_with_control(graph, control)? {
+        Some(order) => {
+            let weights = vec![1usize; n];
+            slack_from_weighted_passes_with_control(graph, &order, &weights, control)
+        }
+        None => slack_via_condensation_with_control(graph, control),
+    }
+}
+
+/// Slack computation shared by the DAG fast path and the condensation path:
+/// runs weighted forward/backward passes and derives slack from them.
+fn slack_from_weighted_passes_with_control(
+    graph: &DiGraph,
+    order: &[usize],
+    weights: &[usize],
+    control: &Control,
+) -> Result<Vec<f64>, Cancelled> {
+    let n = graph.len();
+    let dist_from_start = forward_pass_with_control(graph, order, weights, control)?;
+    let dist_to_end = backward_pass_with_control(graph, order, weights, control)?;
+
+    let longest_path: usize = (0..n)
+        .map(|i| dist_from_start[i] + dist_to_end[i] - weights[i])
+        .max()
+        .unwrap_or(0);
+
+    Ok((0..n)
+        .map(|i| {
+            let path_through_i = dist_from_start[i] + dist_to_end[i] - weights[i];
+            (longest_path - path_through_i) as f64
+        })
+        .collect())
+}
 
-    // Forward pass: longest distance from any start (nodes with no predecessors)
-    // dist_from_start[v] = length of longest path from any root to v
-    let mut dist_from_start = vec![0usize; n];
-    for &v in &order {
+/// Condense `graph` into its SCCs, run the forward/backward passes on the
+/// resulting DAG (weighting each super-node by its member count), then map
+/// every super-node's slack back onto its member nodes.
+fn slack_via_condensation_with_control(
+    graph: &DiGraph,
+    control: &Control,
+) -> Result<Vec<f64>, Cancelled> {
+    let (condensed, members) = condensation_with_control(graph, control)?;
+    let weights: Vec<usize> = members.iter().map(|m| m.len()).collect();
+    let order = topological_sort_with_control(&condensed, control)?
+        .expect("condensation is always a DAG");
+    let comp_slack = slack_from_weighted_passes_with_control(&condensed, &order, &weights, control)?;
+
+    let mut result = vec![0.0; graph.len()];
+    for (comp, member_nodes) in members.iter().enumerate() {
+        for &v in member_nodes {
+            result[v] = comp_slack[comp];
+        }
+    }
+    Ok(result)
+}
+
+/// Longest distance from any root to each node, in topological order.
+/// `weights[v]` is the "length" contributed by node `v` itself (1 for
+/// plain node-count distance, or an SCC's member count in the condensation
+/// path).
+pub(crate) fn forward_pass(graph: &DiGraph, order: &[usize], weights: &[usize]) -> Vec<usize> {
+    forward_pass_with_control(graph, order, weights, &Control::uncancellable())
+        .expect("uncancellable control never returns Cancelled")
+}
+
+/// Control-aware variant of [`forward_pass`], checking for cancellation
+/// before each node is processed and reporting progress every
+/// `control.progress_every` nodes.
+pub(crate) fn forward_pass_with_control(
+    graph: &DiGraph,
+    order: &[usize],
+    weights: &[usize],
+    control: &Control,
+) -> Result<Vec<usize>, Cancelled> {
+    let mut dist_from_start = vec![0usize; graph.len()];
+    for (done, &v) in order.iter().enumerate() {
+        control.tick(done, order.len())?;
         let max_pred = graph
             .predecessors_slice(v)
             .iter()
             .map(|&u| dist_from_start[u])
             .max()
             .unwrap_or(0);
-        dist_from_start[v] = max_pred + 1;
+        dist_from_start[v] = max_pred + weights[v];
     }
+    Ok(dist_from_start)
+}
+
+/// Longest distance from each node to any leaf, in reverse topological
+/// order. See [`forward_pass`] for `weights`.
+pub(crate) fn backward_pass(graph: &DiGraph, order: &[usize], weights: &[usize]) -> Vec<usize> {
+    backward_pass_with_control(graph, order, weights, &Control::uncancellable())
+        .expect("uncancellable control never returns Cancelled")
+}
 
-    // Backward pass: longest distance to any end (nodes with no successors)
-    // dist_to_end[v] = length of longest path from v to any leaf
-    let mut dist_to_end = vec![0usize; n];
-    for &v in order.iter().rev() {
+/// Control-aware variant of [`backward_pass`], checking for cancellation
+/// before each node is processed and reporting progress every
+/// `control.progress_every` nodes.
+pub(crate) fn backward_pass_with_control(
+    graph: &DiGraph,
+    order: &[usize],
+    weights: &[usize],
+    control: &Control,
+) -> Result<Vec<usize>, Cancelled> {
+    let mut dist_to_end = vec![0usize; graph.len()];
+    for (done, &v) in order.iter().rev().enumerate() {
+        control.tick(done, order.len())?;
         let max_succ = graph
             .successors_slice(v)
             .iter()
             .map(|&w| dist_to_end[w])
             .max()
             .unwrap_or(0);
-        dist_to_end[v] = max_succ + 1;
+        dist_to_end[v] = max_succ + weights[v];
     }
-
-    // Find the longest path length in the entire graph
-    // longest_path_length = max(dist_from_start[i] + dist_to_end[i] - 1) for all i
-    // (we subtract 1 because node v is counted in both distances)
-    let longest_path: usize = (0..n)
-        .map(|i| dist_from_start[i] + dist_to_end[i] - 1)
-        .max()
-        .unwrap_or(0);
-
-    // Slack = longest_path - (dist_from_start + dist_to_end - 1)
-    (0..n)
-        .map(|i| {
-            let path_through_i = dist_from_start[i] + dist_to_end[i] - 1;
-            (longest_path - path_through_i) as f64
-        })
-        .collect()
+    Ok(dist_to_end)
 }
 
 /// Get nodes with zero slack (on the critical path).
@@ -91,6 +191,99 @@ pub fn total_float(graph: &DiGraph) -> f64 {
     slack(graph).into_iter().fold(0.0, f64::max)
 }
 
+/// Reconstruct the ordered critical path(s) of `graph`.
+///
+/// `zero_slack_nodes` only reports which nodes are critical; this walks the
+/// "tight" edges between them (an edge `u -> w` where
+/// `dist_from_start[u] + 1 == dist_from_start[w]` and both endpoints have
+/// zero slack) to recover the actual node sequences. Diamonds of equal
+/// length (see `test_slack_diamond`) produce more than one path.
+///
+/// Computes `dist_from_start`/`dist_to_end` with the same forward and
+/// backward passes that `slack` runs, so this stays O(V+E) plus the size of
+/// the output.
+///
+/// `max_paths` caps the number of paths returned, guarding against
+/// combinatorial blowup on graphs with many equal-length diamonds. Returns
+/// an empty vector for cyclic graphs.
+pub fn critical_paths(graph: &DiGraph, max_paths: usize) -> Vec<Vec<usize>> {
+    let n = graph.len();
+    if n == 0 || max_paths == 0 {
+        return Vec::new();
+    }
+
+    let order = match topological_sort(graph) {
+        Some(o) => o,
+        None => return Vec::new(),
+    };
+
+    let weights = vec![1usize; n];
+    let dist_from_start = forward_pass(graph, &order, &weights);
+    let dist_to_end = backward_pass(graph, &order, &weights);
+    let longest_path = (0..n)
+        .map(|i| dist_from_start[i] + dist_to_end[i] - 1)
+        .max()
+        .unwrap_or(0);
+
+    let is_critical = |v: usize| dist_from_start[v] + dist_to_end[v] - 1 == longest_path;
+    let is_tight = |u: usize, w: usize| is_critical(u) && is_critical(w) && dist_from_start[u] + 1 == dist_from_start[w];
+
+    let critical_sources: Vec<usize> = (0..n)
+        .filter(|&v| {
+            is_critical(v)
+                && !graph
+                    .predecessors_slice(v)
+                    .iter()
+                    .any(|&u| is_tight(u, v))
+        })
+        .collect();
+
+    let mut paths = Vec::new();
+    for source in critical_sources {
+        let mut path = vec![source];
+        walk_tight_edges(graph, &is_tight, &mut path, max_paths, &mut paths);
+        if paths.len() >= max_paths {
+            break;
+        }
+    }
+    paths.truncate(max_paths);
+    paths
+}
+
+/// Depth-first enumeration of maximal tight-edge paths starting at `path[0]`.
+fn walk_tight_edges(
+    graph: &DiGraph,
+    is_tight: &impl Fn(usize, usize) -> bool,
+    path: &mut Vec<usize>,
+    max_paths: usize,
+    out: &mut Vec<Vec<usize>>,
+) {
+    if out.len() >= max_paths {
+        return;
+    }
+    let v = *path.last().unwrap();
+    let tight_succs: Vec<usize> = graph
+        .successors_slice(v)
+        .iter()
+        .copied()
+        .filter(|&w| is_tight(v, w))
+        .collect();
+
+    if tight_succs.is_empty() {
+        out.push(path.clone());
+        return;
+    }
+
+    for w in tight_succs {
+        path.push(w);
+        walk_tight_edges(graph, is_tight, path, max_paths, out);
+        path.pop();
+        if out.len() >= max_paths {
+            return;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,8 +433,8 @@ mod tests {
 
     #[test]
     fn test_slack_cyclic() {
-        // a -> b -> c -> a
-        // Should return zeros for cyclic graphs
+        // a -> b -> c -> a is a single SCC, so condensation collapses it to
+        // one super-node: every member is equally critical, hence zero slack.
         let mut graph = DiGraph::new();
         let a = graph.add_node("a");
         let b = graph.add_node("b");
@@ -254,6 +447,37 @@ mod tests {
         assert_eq!(s, vec![0.0, 0.0, 0.0]);
     }
 
+    #[test]
+    fn test_slack_cyclic_with_acyclic_tail_has_nonzero_slack() {
+        // a -> b -> c -> a (cycle, weight 3) feeds two downstream branches:
+        //   a -> d -> f   (length 2 past the cycle, critical)
+        //   a -> e        (length 1 past the cycle, has slack)
+        // Condensation collapses the cycle to one weight-3 super-node; the
+        // d->f branch is critical while e should carry nonzero slack rather
+        // than the old blanket all-zeros fallback.
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        let f = graph.add_node("f");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+        graph.add_edge(a, d);
+        graph.add_edge(d, f);
+        graph.add_edge(a, e);
+
+        let s = slack(&graph);
+        assert_eq!(s[a], 0.0);
+        assert_eq!(s[b], 0.0);
+        assert_eq!(s[c], 0.0);
+        assert_eq!(s[d], 0.0);
+        assert_eq!(s[f], 0.0);
+        assert!(s[e] > 0.0, "expected e to have slack, got {}", s[e]);
+    }
+
     #[test]
     fn test_zero_slack_nodes() {
         // a -> b -> c
@@ -318,4 +542,143 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_critical_paths_chain() {
+        // a -> b -> c, single critical path
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let paths = critical_paths(&graph, 10);
+        assert_eq!(paths, vec![vec![a, b, c]]);
+    }
+
+    #[test]
+    fn test_critical_paths_diamond_has_two_paths() {
+        //     a
+        //    / \
+        //   b   c
+        //    \ /
+        //     d
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let mut paths = critical_paths(&graph, 10);
+        paths.sort();
+        assert_eq!(paths, vec![vec![a, b, d], vec![a, c, d]]);
+    }
+
+    #[test]
+    fn test_critical_paths_ignores_slack_nodes() {
+        // Critical path a->c->d->e (length 4); a->b->f has slack so is excluded.
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        let f = graph.add_node("f");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, f);
+        graph.add_edge(c, d);
+        graph.add_edge(d, e);
+
+        let paths = critical_paths(&graph, 10);
+        assert_eq!(paths, vec![vec![a, c, d, e]]);
+    }
+
+    #[test]
+    fn test_critical_paths_respects_max_paths_cap() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let paths = critical_paths(&graph, 1);
+        assert_eq!(paths.len(), 1);
+    }
+
+    #[test]
+    fn test_critical_paths_cyclic_is_empty() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        assert!(critical_paths(&graph, 10).is_empty());
+    }
+
+    #[test]
+    fn test_slack_with_control_cancels() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let always_cancel = || true;
+        let control = Control {
+            should_cancel: &always_cancel,
+            on_progress: None,
+            progress_every: 1,
+        };
+        assert_eq!(slack_with_control(&graph, &control), Err(Cancelled));
+    }
+
+    #[test]
+    fn test_slack_with_control_reports_progress() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let never_cancel = || false;
+        let calls = std::cell::RefCell::new(Vec::new());
+        let on_progress = |done: usize, total: usize| calls.borrow_mut().push((done, total));
+        let control = Control {
+            should_cancel: &never_cancel,
+            on_progress: Some(&on_progress),
+            progress_every: 1,
+        };
+
+        let s = slack_with_control(&graph, &control).unwrap();
+        assert_eq!(s, vec![0.0, 0.0, 0.0]);
+        assert!(!calls.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_slack_with_control_cancels_on_cyclic_condensation_path() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        let always_cancel = || true;
+        let control = Control {
+            should_cancel: &always_cancel,
+            on_progress: None,
+            progress_every: 1,
+        };
+        assert_eq!(slack_with_control(&graph, &control), Err(Cancelled));
+    }
 }
@@ -0,0 +1,145 @@
+//! Topological sort.
+//!
+//! Kahn's algorithm: repeatedly peel off nodes with no remaining
+//! unprocessed predecessors. A `Some` order exists iff `graph` is acyclic;
+//! cyclic graphs return `None`, which
+//! [`slack`](crate::algorithms::slack::slack) uses to fall back to
+//! condensation and [`cpm`](crate::algorithms::cpm::cpm) passes on as its
+//! own `None`.
+
+use crate::algorithms::control::{Cancelled, Control};
+use crate::graph::DiGraph;
+use std::collections::VecDeque;
+
+/// Topologically sort `graph`, or return `None` if it contains a cycle.
+pub fn topological_sort(graph: &DiGraph) -> Option<Vec<usize>> {
+    topological_sort_with_control(graph, &Control::uncancellable())
+        .expect("uncancellable control never returns Cancelled")
+}
+
+/// Like [`topological_sort`], but checks `control.should_cancel` periodically
+/// while peeling off nodes, returning `Err(Cancelled)` if it fires, and
+/// invokes `control.on_progress` every `control.progress_every` nodes
+/// peeled.
+pub fn topological_sort_with_control(
+    graph: &DiGraph,
+    control: &Control,
+) -> Result<Option<Vec<usize>>, Cancelled> {
+    let n = graph.len();
+    let mut in_degree = vec![0usize; n];
+    for v in 0..n {
+        for &w in graph.successors_slice(v) {
+            in_degree[w] += 1;
+        }
+    }
+
+    let mut queue: VecDeque<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+    let mut order = Vec::with_capacity(n);
+
+    while let Some(v) = queue.pop_front() {
+        control.tick(order.len(), n)?;
+        order.push(v);
+        for &w in graph.successors_slice(v) {
+            in_degree[w] -= 1;
+            if in_degree[w] == 0 {
+                queue.push_back(w);
+            }
+        }
+    }
+
+    if order.len() == n {
+        Ok(Some(order))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topological_sort_chain() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        assert_eq!(topological_sort(&graph), Some(vec![a, b, c]));
+    }
+
+    #[test]
+    fn test_topological_sort_cyclic_is_none() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        assert_eq!(topological_sort(&graph), None);
+    }
+
+    #[test]
+    fn test_topological_sort_diamond() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let order = topological_sort(&graph).unwrap();
+        let pos = |v: usize| order.iter().position(|&x| x == v).unwrap();
+        assert!(pos(a) < pos(b));
+        assert!(pos(a) < pos(c));
+        assert!(pos(b) < pos(d));
+        assert!(pos(c) < pos(d));
+    }
+
+    #[test]
+    fn test_topological_sort_with_control_cancels() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let always_cancel = || true;
+        let control = Control {
+            should_cancel: &always_cancel,
+            on_progress: None,
+            progress_every: 1,
+        };
+        assert_eq!(
+            topological_sort_with_control(&graph, &control),
+            Err(Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_topological_sort_with_control_reports_progress() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let never_cancel = || false;
+        let calls = std::cell::RefCell::new(Vec::new());
+        let on_progress = |done: usize, total: usize| calls.borrow_mut().push((done, total));
+        let control = Control {
+            should_cancel: &never_cancel,
+            on_progress: Some(&on_progress),
+            progress_every: 1,
+        };
+
+        let order = topological_sort_with_control(&graph, &control)
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.len(), 2);
+        assert!(!calls.borrow().is_empty());
+    }
+}